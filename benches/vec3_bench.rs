@@ -0,0 +1,66 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// vec3.rs is written for the bin target: pulling it in here by path means
+// this build never enables `#[cfg(test)]`: the assert_approx_eq! macro is
+// compiled out, and the surface only the tests use (ApproxEq, swizzle2)
+// reads as dead code.
+#[path = "../src/vec3.rs"]
+#[allow(dead_code, unused_macros, unused_imports)]
+mod vec3;
+
+use vec3::{Vec3A, Vec3f};
+
+const N: usize = 1 << 16;
+
+fn bench_vec3(c: &mut Criterion) {
+    let a: Vec<Vec3f> = (0..N).map(|i| Vec3f::splat(i as f32)).collect();
+    let b: Vec<Vec3f> = (0..N).map(|i| Vec3f::splat((i + 1) as f32)).collect();
+
+    c.bench_function("vec3_add", |bencher| {
+        bencher.iter(|| {
+            let mut acc = Vec3f::zero();
+            for (x, y) in a.iter().zip(b.iter()) {
+                acc += black_box(*x) + black_box(*y);
+            }
+            black_box(acc)
+        })
+    });
+
+    c.bench_function("vec3_dot", |bencher| {
+        bencher.iter(|| {
+            let mut acc = 0.0;
+            for (x, y) in a.iter().zip(b.iter()) {
+                acc += Vec3f::dot(black_box(x), black_box(y));
+            }
+            black_box(acc)
+        })
+    });
+}
+
+fn bench_vec3a(c: &mut Criterion) {
+    let a: Vec<Vec3A> = (0..N).map(|i| Vec3A::splat(i as f32)).collect();
+    let b: Vec<Vec3A> = (0..N).map(|i| Vec3A::splat((i + 1) as f32)).collect();
+
+    c.bench_function("vec3a_add", |bencher| {
+        bencher.iter(|| {
+            let mut acc = Vec3A::ZERO;
+            for (x, y) in a.iter().zip(b.iter()) {
+                acc += black_box(*x) + black_box(*y);
+            }
+            black_box(acc)
+        })
+    });
+
+    c.bench_function("vec3a_dot", |bencher| {
+        bencher.iter(|| {
+            let mut acc = 0.0;
+            for (x, y) in a.iter().zip(b.iter()) {
+                acc += Vec3A::dot(black_box(x), black_box(y));
+            }
+            black_box(acc)
+        })
+    });
+}
+
+criterion_group!(benches, bench_vec3, bench_vec3a);
+criterion_main!(benches);
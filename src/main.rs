@@ -4,13 +4,15 @@ use std::{
     io::{BufWriter, Write},
 };
 
+use bytemuck::{Pod, Zeroable};
+
 mod vec3;
 
 fn main() -> std::io::Result<()> {
     let mut buffer = BufWriter::new(File::create("out.ppm")?);
     let img = to_draw(256, 256);
 
-    write!(buffer, "{}", Ppm(&img))?;
+    img.write(PpmFormat::Binary, &mut buffer)?;
     buffer.flush()?;
     Ok(())
 }
@@ -30,7 +32,8 @@ fn to_draw(width: usize, height: usize) -> Image {
     })
 }
 
-#[derive(Default, Debug)]
+#[repr(C)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
 struct Pixel {
     r: u8,
     g: u8,
@@ -38,16 +41,14 @@ struct Pixel {
 }
 
 struct Image {
-    pixels: Vec<Vec<Pixel>>,
+    pixels: Vec<Pixel>,
     height: usize,
     width: usize,
 }
 
 impl Image {
     pub fn new(height: usize, width: usize) -> Self {
-        let pixels: Vec<Vec<Pixel>> = (0..height)
-            .map(|_| (0..width).map(|_| Pixel::default()).collect())
-            .collect();
+        let pixels = vec![Pixel::default(); width * height];
 
         Self {
             pixels,
@@ -61,9 +62,12 @@ impl Image {
         width: usize,
         pix_init: impl Fn(usize, usize) -> Pixel,
     ) -> Self {
-        let pixels = (0..height)
-            .map(|row| (0..width).map(|col| pix_init(row, col)).collect())
-            .collect();
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                pixels.push(pix_init(row, col));
+            }
+        }
 
         Self {
             pixels,
@@ -71,6 +75,33 @@ impl Image {
             width,
         }
     }
+
+    /// Flat offset of the pixel at `(row, col)` into `self.pixels`.
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * self.width + col
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &Pixel {
+        &self.pixels[self.index(row, col)]
+    }
+
+    pub fn write(&self, format: PpmFormat, writer: &mut impl Write) -> std::io::Result<()> {
+        match format {
+            PpmFormat::Ascii => write!(writer, "{}", Ppm(self)),
+            PpmFormat::Binary => {
+                writeln!(writer, "P6")?;
+                writeln!(writer, "{} {}", self.width, self.height)?;
+                writeln!(writer, "255")?;
+                writer.write_all(bytemuck::cast_slice(&self.pixels))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PpmFormat {
+    Ascii,
+    Binary,
 }
 
 struct Ppm<'a, T>(&'a T);
@@ -93,9 +124,9 @@ impl Display for Ppm<'_, Image> {
         writeln!(formatter, "{} {}", self.0.width, self.0.height)?;
         writeln!(formatter, "255")?; // maximum color value
 
-        for row in &self.0.pixels {
-            for pixel in row {
-                writeln!(formatter, "{}", Ppm(pixel))?;
+        for row in 0..self.0.height {
+            for col in 0..self.0.width {
+                writeln!(formatter, "{}", Ppm(self.0.get(row, col)))?;
             }
         }
         Ok(())
@@ -139,4 +170,39 @@ P3
 "
         );
     }
+
+    #[test]
+    fn test_write_ascii_matches_display() {
+        let img = Image::new_assign(2, 2, |i, j| Pixel {
+            r: i as u8,
+            g: j as u8,
+            b: i as u8,
+        });
+
+        let mut bytes = Vec::new();
+        img.write(PpmFormat::Ascii, &mut bytes).unwrap();
+
+        assert_eq!(String::from_utf8(bytes).unwrap(), format!("{}", Ppm(&img)));
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let img = Image::new_assign(2, 2, |i, j| Pixel {
+            r: i as u8,
+            g: j as u8,
+            b: i as u8,
+        });
+
+        let mut bytes = Vec::new();
+        img.write(PpmFormat::Binary, &mut bytes).unwrap();
+
+        let mut lines = bytes.splitn(4, |&b| b == b'\n');
+        assert_eq!(lines.next().unwrap(), b"P6");
+        assert_eq!(lines.next().unwrap(), b"2 2");
+        assert_eq!(lines.next().unwrap(), b"255");
+        let pixel_bytes = lines.next().unwrap();
+
+        let pixels: &[Pixel] = bytemuck::cast_slice(pixel_bytes);
+        assert_eq!(pixels, img.pixels.as_slice());
+    }
 }
@@ -1,257 +1,684 @@
 use std::{
     fmt::Debug,
+    marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-#[derive(Copy, Clone, PartialEq, PartialOrd)]
-pub struct Vec3 {
+use num_traits::Float;
+
+/// Placeholder unit for `Vec3<T, U>` when the caller doesn't care about
+/// distinguishing coordinate spaces, mirroring euclid's `UnknownUnit`.
+pub struct UnknownUnit;
+
+/// A 3-component vector over scalar type `T`, tagged with a zero-sized
+/// unit `U` so the type system can keep vectors from different coordinate
+/// spaces (world space, camera space, ...) from being mixed by accident.
+pub struct Vec3<T, U = UnknownUnit> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    _unit: PhantomData<U>,
+}
+
+/// `f32` vector with no unit tag; the shape every existing call site used
+/// before `Vec3` grew a scalar/unit parameter.
+pub type Vec3f = Vec3<f32, UnknownUnit>;
+
+impl<T: Copy, U> Copy for Vec3<T, U> {}
+
+impl<T: Clone, U> Clone for Vec3<T, U> {
+    fn clone(&self) -> Self {
+        Vec3::new(self.x.clone(), self.y.clone(), self.z.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Vec3<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl<T: PartialOrd, U> PartialOrd for Vec3<T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (&self.x, &self.y, &self.z).partial_cmp(&(&other.x, &other.y, &other.z))
+    }
+}
+
+impl<T: Debug, U> Debug for Vec3<T, U> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
+    }
+}
+
+impl<T, U> Vec3<T, U> {
+    pub fn new(x: T, y: T, z: T) -> Vec3<T, U> {
+        Vec3 {
+            x,
+            y,
+            z,
+            _unit: PhantomData,
+        }
+    }
+
+    pub fn cast_unit<V>(self) -> Vec3<T, V> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+}
+
+impl<T: Copy, U> Vec3<T, U> {
+    pub fn splat(value: T) -> Vec3<T, U> {
+        Vec3::new(value, value, value)
+    }
+
+    pub fn with_x(self, x: T) -> Vec3<T, U> {
+        Vec3::new(x, self.y, self.z)
+    }
+
+    pub fn with_y(self, y: T) -> Vec3<T, U> {
+        Vec3::new(self.x, y, self.z)
+    }
+
+    pub fn with_z(self, z: T) -> Vec3<T, U> {
+        Vec3::new(self.x, self.y, z)
+    }
+}
+
+impl<T: num_traits::Zero + Copy, U> Vec3<T, U> {
+    pub fn zero() -> Vec3<T, U> {
+        Vec3::splat(T::zero())
+    }
+}
+
+impl<T: num_traits::One + Copy, U> Vec3<T, U> {
+    pub fn one() -> Vec3<T, U> {
+        Vec3::splat(T::one())
+    }
+}
+
+impl<T: Add<Output = T> + Mul<Output = T> + Copy, U> Vec3<T, U> {
+    pub fn len_squared(&self) -> T {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn dot(a: &Vec3<T, U>, b: &Vec3<T, U>) -> T {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+}
+
+impl<T: Sub<Output = T> + Mul<Output = T> + Copy, U> Vec3<T, U> {
+    pub fn cross(a: &Vec3<T, U>, b: &Vec3<T, U>) -> Vec3<T, U> {
+        Vec3::new(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x,
+        )
+    }
+}
+
+impl<T: Float, U> Vec3<T, U> {
+    pub fn len(&self) -> T {
+        self.len_squared().sqrt()
+    }
+
+    pub fn normalize(self) -> Vec3<T, U> {
+        self / self.len()
+    }
+
+    pub fn min(&self, rhs: &Vec3<T, U>) -> Vec3<T, U> {
+        Vec3::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+    }
+
+    pub fn max(&self, rhs: &Vec3<T, U>) -> Vec3<T, U> {
+        Vec3::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+    }
+
+    // One rounding instead of two per component.
+    pub fn mul_add(self, mul: Vec3<T, U>, add: Vec3<T, U>) -> Vec3<T, U> {
+        Vec3::new(
+            self.x.mul_add(mul.x, add.x),
+            self.y.mul_add(mul.y, add.y),
+            self.z.mul_add(mul.z, add.z),
+        )
+    }
+
+    /// Linear interpolation towards `rhs` by scalar `t`.
+    pub fn lerp(self, rhs: Vec3<T, U>, t: T) -> Vec3<T, U> {
+        self.mul_add(Vec3::splat(T::one() - t), rhs * t)
+    }
+
+    /// Linear interpolation towards `rhs` with a per-component factor.
+    pub fn lerp_vec3(self, rhs: Vec3<T, U>, t: Vec3<T, U>) -> Vec3<T, U> {
+        self.mul_add(Vec3::splat(T::one()) - t, rhs * t)
+    }
+
+    pub fn abs(&self) -> Vec3<T, U> {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+}
+
+// Like euclid's ApproxEq: tolerant comparison for floating-point Vec3s.
+pub trait ApproxEq<Eps = Self> {
+    fn approx_epsilon() -> Eps;
+    fn approx_eq_eps(&self, other: &Self, eps: &Eps) -> bool;
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, &Self::approx_epsilon())
+    }
+}
+
+impl<T: Float, U> ApproxEq for Vec3<T, U> {
+    fn approx_epsilon() -> Vec3<T, U> {
+        Vec3::splat(T::epsilon().sqrt())
+    }
+
+    fn approx_eq_eps(&self, other: &Self, eps: &Vec3<T, U>) -> bool {
+        let diff = (*self - *other).abs();
+        diff.x <= eps.x && diff.y <= eps.y && diff.z <= eps.z
+    }
+}
+
+// `ApproxEq`-based companion to `assert_eq!`; only used by tests below.
+#[cfg(test)]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr) => {{
+        let (left, right) = (&$left, &$right);
+        assert!(
+            left.approx_eq(right),
+            "assertion failed: `(left ≈ right)`\n  left: `{:?}`\n right: `{:?}`",
+            left,
+            right
+        );
+    }};
+    ($left:expr, $right:expr, $eps:expr) => {{
+        let (left, right, eps) = (&$left, &$right, &$eps);
+        assert!(
+            left.approx_eq_eps(right, eps),
+            "assertion failed: `(left ≈ right)` within `{:?}`\n  left: `{:?}`\n right: `{:?}`",
+            eps,
+            left,
+            right
+        );
+    }};
+}
+
+impl<T: Copy, U> Vec3<T, U> {
+    fn component(&self, index: usize) -> T {
+        match index {
+            0 => self.x,
+            1 => self.y,
+            2 => self.z,
+            _ => panic!("swizzle index {index} out of range, expected 0..=2"),
+        }
+    }
+}
+
+/// GLSL-style component reordering/broadcasting, so call sites that used
+/// to write `Vec3::new(v.z, v.y, v.x)` can write `v.zyx()` instead.
+pub trait Swizzle<T, U> {
+    fn swizzle3<const X: usize, const Y: usize, const Z: usize>(&self) -> Vec3<T, U>;
+    fn swizzle2<const X: usize, const Y: usize>(&self) -> (T, T);
+}
+
+impl<T: Copy, U> Swizzle<T, U> for Vec3<T, U> {
+    fn swizzle3<const X: usize, const Y: usize, const Z: usize>(&self) -> Vec3<T, U> {
+        debug_assert!(X < 3 && Y < 3 && Z < 3, "swizzle3 index out of range");
+        Vec3::new(self.component(X), self.component(Y), self.component(Z))
+    }
+
+    fn swizzle2<const X: usize, const Y: usize>(&self) -> (T, T) {
+        debug_assert!(X < 3 && Y < 3, "swizzle2 index out of range");
+        (self.component(X), self.component(Y))
+    }
+}
+
+impl<T: Copy, U> Vec3<T, U> {
+    pub fn xxx(&self) -> Vec3<T, U> {
+        self.swizzle3::<0, 0, 0>()
+    }
+
+    pub fn yyy(&self) -> Vec3<T, U> {
+        self.swizzle3::<1, 1, 1>()
+    }
+
+    pub fn zzz(&self) -> Vec3<T, U> {
+        self.swizzle3::<2, 2, 2>()
+    }
+
+    pub fn xzy(&self) -> Vec3<T, U> {
+        self.swizzle3::<0, 2, 1>()
+    }
+
+    pub fn yxz(&self) -> Vec3<T, U> {
+        self.swizzle3::<1, 0, 2>()
+    }
+
+    pub fn yzx(&self) -> Vec3<T, U> {
+        self.swizzle3::<1, 2, 0>()
+    }
+
+    pub fn zxy(&self) -> Vec3<T, U> {
+        self.swizzle3::<2, 0, 1>()
+    }
+
+    pub fn zyx(&self) -> Vec3<T, U> {
+        self.swizzle3::<2, 1, 0>()
+    }
+}
+
+macro_rules! impl_binary_operations {
+  // $Trait -> `Add`, $binary_fn -> `add`, $binary_symbol -> `+`
+  ($Trait:ident $binary_fn:ident $binary_symbol:tt) => {
+    // All other implementations forward through to this implementation
+    // a: &Vec3<T, U>, b: &Vec3<T, U>
+    impl<'a, 'b, T: $Trait<Output = T> + Copy, U> $Trait<&'a Vec3<T, U>> for &'b Vec3<T, U> {
+      type Output = Vec3<T, U>;
+      fn $binary_fn(self, rhs: &'a Vec3<T, U>) -> Vec3<T, U> {
+        Vec3::new(
+          self.x $binary_symbol rhs.x,
+          self.y $binary_symbol rhs.y,
+          self.z $binary_symbol rhs.z,
+        )
+      }
+    }
+
+    // a: Vec3<T, U>, b: Vec3<T, U>
+    impl<T: $Trait<Output = T> + Copy, U> $Trait<Vec3<T, U>> for Vec3<T, U> {
+      type Output = Vec3<T, U>;
+
+      #[inline]
+      fn $binary_fn(self, rhs: Vec3<T, U>) -> Vec3<T, U> {
+        &self $binary_symbol &rhs
+      }
+    }
+
+    // a: Vec3<T, U>, b: &Vec3<T, U>
+    impl<'a, T: $Trait<Output = T> + Copy, U> $Trait<&'a Vec3<T, U>> for Vec3<T, U> {
+      type Output = Vec3<T, U>;
+
+      #[inline]
+      fn $binary_fn(self, rhs: &'a Vec3<T, U>) -> Vec3<T, U> {
+        &self $binary_symbol rhs
+      }
+    }
+
+    // a: &Vec3<T, U>, b: Vec3<T, U>
+    impl<'a, T: $Trait<Output = T> + Copy, U> $Trait<Vec3<T, U>> for &'a Vec3<T, U> {
+      type Output = Vec3<T, U>;
+
+      #[inline]
+      fn $binary_fn(self, rhs: Vec3<T, U>) -> Vec3<T, U> {
+        self $binary_symbol &rhs
+      }
+    }
+
+    // Scalar on the right, e.g. `vector * 2.0`. A scalar-on-the-left
+    // (`2.0 * vector`) impl isn't possible here: with `T` fully generic,
+    // `impl<T, U> Trait<Vec3<T, U>> for T` falls foul of the orphan rules.
+    impl<'a, T: $Trait<Output = T> + Copy, U> $Trait<T> for &'a Vec3<T, U> {
+      type Output = Vec3<T, U>;
+
+      fn $binary_fn(self, rhs: T) -> Vec3<T, U> {
+        Vec3::new(
+          self.x $binary_symbol rhs,
+          self.y $binary_symbol rhs,
+          self.z $binary_symbol rhs
+        )
+      }
+    }
+
+    impl<T: $Trait<Output = T> + Copy, U> $Trait<T> for Vec3<T, U> {
+      type Output = Vec3<T, U>;
+
+      #[inline]
+      fn $binary_fn(self, rhs: T) -> Vec3<T, U> {
+        &self $binary_symbol rhs
+      }
+    }
+  };
+}
+
+macro_rules! impl_unary_operations {
+  ($Trait:ident $binary_fn:ident $binary_symbol:tt) => {
+
+    impl<'a, T: $Trait<Output = T> + Copy, U> $Trait for &'a Vec3<T, U> {
+      type Output = Vec3<T, U>;
+
+      fn $binary_fn(self) -> Vec3<T, U> {
+        Vec3::new(
+          $binary_symbol self.x,
+          $binary_symbol self.y,
+          $binary_symbol self.z,
+        )
+      }
+    }
+
+    impl<T: $Trait<Output = T> + Copy, U> $Trait for Vec3<T, U> {
+      type Output = Vec3<T, U>;
+
+      #[inline]
+      fn $binary_fn(self) -> Vec3<T, U> {
+        $binary_symbol &self
+      }
+    }
+  };
+}
+
+// The four op-assign impls below can't go through a macro as cleanly as
+// the binary ops: the trait bound (e.g. `T: Add<Output = T>`) and the
+// assign trait (`AddAssign`) don't share a name to key a `tt` off of.
+impl_binary_operations!(Add add +);
+impl_binary_operations!(Sub sub -);
+impl_binary_operations!(Mul mul *);
+impl_binary_operations!(Div div /);
+
+impl<'a, T: Add<Output = T> + Copy, U> AddAssign<&'a Vec3<T, U>> for Vec3<T, U> {
+    fn add_assign(&mut self, rhs: &'a Vec3<T, U>) {
+        *self = Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z);
+    }
+}
+
+impl<T: Add<Output = T> + Copy, U> AddAssign for Vec3<T, U> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vec3<T, U>) {
+        *self = *self + rhs
+    }
+}
+
+impl<'a, T: Sub<Output = T> + Copy, U> SubAssign<&'a Vec3<T, U>> for Vec3<T, U> {
+    fn sub_assign(&mut self, rhs: &'a Vec3<T, U>) {
+        *self = Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z);
+    }
+}
+
+impl<T: Sub<Output = T> + Copy, U> SubAssign for Vec3<T, U> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vec3<T, U>) {
+        *self = *self - rhs
+    }
+}
+
+impl<'a, T: Mul<Output = T> + Copy, U> MulAssign<&'a Vec3<T, U>> for Vec3<T, U> {
+    fn mul_assign(&mut self, rhs: &'a Vec3<T, U>) {
+        *self = Vec3::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z);
+    }
+}
+
+impl<T: Mul<Output = T> + Copy, U> MulAssign for Vec3<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Vec3<T, U>) {
+        *self = *self * rhs
+    }
+}
+
+impl<'a, T: Div<Output = T> + Copy, U> DivAssign<&'a Vec3<T, U>> for Vec3<T, U> {
+    fn div_assign(&mut self, rhs: &'a Vec3<T, U>) {
+        *self = Vec3::new(self.x / rhs.x, self.y / rhs.y, self.z / rhs.z);
+    }
+}
+
+impl<T: Div<Output = T> + Copy, U> DivAssign for Vec3<T, U> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Vec3<T, U>) {
+        *self = *self / rhs
+    }
+}
+
+impl_unary_operations!(Neg neg -);
+
+// `_w` is padding to hit the 16-byte alignment; dot/len_squared must ignore it.
+#[derive(Copy, Clone, PartialEq)]
+#[repr(align(16))]
+pub struct Vec3A {
     pub x: f32,
     pub y: f32,
     pub z: f32,
+    _w: f32,
 }
 
-impl Debug for Vec3 {
+impl Debug for Vec3A {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(formatter, "({}, {}, {})", self.x, self.y, self.z)
+        write!(formatter, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
     }
 }
 
-impl Vec3 {
-    pub const ZERO: Vec3 = Vec3 {
+impl Vec3A {
+    // `Vec3<T, U>` exposes `zero()`/`one()` methods instead of consts because a
+    // generic const can't call `T::zero()`; `Vec3A` is concrete, so `ZERO`/`ONE`
+    // stay consts here rather than taking on that constraint unnecessarily.
+    pub const ZERO: Vec3A = Vec3A {
         x: 0.0,
         y: 0.0,
         z: 0.0,
+        _w: 0.0,
     };
-    pub const ONE: Vec3 = Vec3 {
+    pub const ONE: Vec3A = Vec3A {
         x: 1.0,
         y: 1.0,
         z: 1.0,
+        _w: 0.0,
     };
 
-    pub fn splat(value: f32) -> Vec3 {
-        Vec3 {
-            x: value,
-            y: value,
-            z: value,
-        }
+    pub fn splat(value: f32) -> Vec3A {
+        Vec3A::new(value, value, value)
     }
 
-    pub fn new(x: f32, y: f32, z: f32) -> Vec3 {
-        Vec3 { x, y, z }
+    pub fn new(x: f32, y: f32, z: f32) -> Vec3A {
+        Vec3A { x, y, z, _w: 0.0 }
     }
 
-    pub fn with_x(self, x: f32) -> Vec3 {
-        return Vec3 {
-            x: x,
-            y: self.y,
-            z: self.z,
-        };
+    pub fn dot(a: &Vec3A, b: &Vec3A) -> f32 {
+        a.x * b.x + a.y * b.y + a.z * b.z
     }
 
-    pub fn with_y(self, y: f32) -> Vec3 {
-        return Vec3 {
-            x: self.x,
-            y: y,
-            z: self.z,
-        };
+    pub fn cross(a: &Vec3A, b: &Vec3A) -> Vec3A {
+        Vec3A::new(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x,
+        )
     }
 
-    pub fn with_z(self, z: f32) -> Vec3 {
-        return Vec3 {
-            x: self.x,
-            y: self.y,
-            z: z,
-        };
+    pub fn len_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
     }
 
-    pub fn normalize(self) -> Vec3 {
-        self / self.len()
+    pub fn len(&self) -> f32 {
+        self.len_squared().sqrt()
     }
 
-    pub fn len(&self) -> f32 {
-        return self.len_squared().sqrt();
+    pub fn normalize(self) -> Vec3A {
+        self / self.len()
     }
 
-    pub fn min(&self, rhs: &Vec3) -> Vec3 {
-        return Vec3 {
-            x: self.x.min(rhs.x),
-            y: self.y.min(rhs.y),
-            z: self.z.min(rhs.z),
-        };
+    pub fn mul_add(self, mul: Vec3A, add: Vec3A) -> Vec3A {
+        Vec3A {
+            x: self.x.mul_add(mul.x, add.x),
+            y: self.y.mul_add(mul.y, add.y),
+            z: self.z.mul_add(mul.z, add.z),
+            _w: self._w.mul_add(mul._w, add._w),
+        }
     }
 
-    pub fn max(&self, rhs: &Vec3) -> Vec3 {
-        return Vec3 {
-            x: self.x.max(rhs.x),
-            y: self.y.max(rhs.y),
-            z: self.z.max(rhs.z),
-        };
+    pub fn lerp(self, rhs: Vec3A, t: f32) -> Vec3A {
+        self.mul_add(Vec3A::splat(1.0 - t), rhs * t)
     }
 
-    pub fn len_squared(&self) -> f32 {
-        return self.x * self.x + self.y * self.y + self.z * self.z;
+    pub fn lerp_vec3(self, rhs: Vec3A, t: Vec3A) -> Vec3A {
+        self.mul_add(Vec3A::splat(1.0) - t, rhs * t)
     }
+}
 
-    pub fn dot(a: &Vec3, b: &Vec3) -> f32 {
-        return a.x * b.x + a.y * b.y + a.z * b.z;
+impl From<Vec3f> for Vec3A {
+    fn from(v: Vec3f) -> Vec3A {
+        Vec3A::new(v.x, v.y, v.z)
     }
+}
 
-    pub fn cross(a: &Vec3, b: &Vec3) -> Vec3 {
-        return Vec3 {
-            x: a.y * b.z - a.z * b.y,
-            y: a.z * b.x - a.x * b.z,
-            z: a.x * b.y - a.y * b.x,
-        };
+impl From<Vec3A> for Vec3f {
+    fn from(v: Vec3A) -> Vec3f {
+        Vec3f::new(v.x, v.y, v.z)
     }
 }
 
-macro_rules! impl_binary_operations {
-  // $VectorType -> `Vec3`
-  // $Trait -> `Add`, $binary_fn -> `add`, $binary_symbol -> `+`
-  ($VectorType:ident $Trait:ident $binary_fn:ident $binary_symbol:tt) => {
-    // All other implementations forward through to this implementation
-    // a: &$VectorType, b: &$VectorType
-    impl<'a, 'b> $Trait<&'a $VectorType> for &'b $VectorType {
-      type Output = $VectorType;
-      fn $binary_fn(self, rhs: &'a $VectorType) -> $VectorType {
-        $VectorType {
+// Vec3A is a concrete f32 type (not generic over T/U like Vec3), so unlike
+// `impl_binary_operations!` it can implement the commutative scalar-on-the-
+// left form (`2.0 * v`) without running into the orphan rules.
+macro_rules! impl_vec3a_binary_operations {
+  ($Trait:ident $binary_fn:ident $binary_symbol:tt) => {
+    impl<'a, 'b> $Trait<&'a Vec3A> for &'b Vec3A {
+      type Output = Vec3A;
+      fn $binary_fn(self, rhs: &'a Vec3A) -> Vec3A {
+        Vec3A {
           x: self.x $binary_symbol rhs.x,
           y: self.y $binary_symbol rhs.y,
           z: self.z $binary_symbol rhs.z,
+          _w: self._w $binary_symbol rhs._w,
         }
       }
     }
 
-    // a: $VectorType, b: $VectorType
-    impl $Trait<$VectorType> for $VectorType {
-      type Output = $VectorType;
+    impl $Trait<Vec3A> for Vec3A {
+      type Output = Vec3A;
 
       #[inline]
-      fn $binary_fn(self, rhs: $VectorType) -> $VectorType {
+      fn $binary_fn(self, rhs: Vec3A) -> Vec3A {
         &self $binary_symbol &rhs
       }
     }
 
-    // a: $VectorType, b: &$VectorType
-    impl<'a> $Trait<&'a $VectorType> for $VectorType {
-      type Output = $VectorType;
+    impl<'a> $Trait<&'a Vec3A> for Vec3A {
+      type Output = Vec3A;
 
       #[inline]
-      fn $binary_fn(self, rhs: &'a $VectorType) -> $VectorType {
+      fn $binary_fn(self, rhs: &'a Vec3A) -> Vec3A {
         &self $binary_symbol rhs
       }
     }
 
-    // a: &$VectorType, b: $VectorType
-    impl<'a> $Trait<$VectorType> for &'a $VectorType {
-      type Output = $VectorType;
+    impl<'a> $Trait<Vec3A> for &'a Vec3A {
+      type Output = Vec3A;
 
       #[inline]
-      fn $binary_fn(self, rhs: $VectorType) -> $VectorType {
+      fn $binary_fn(self, rhs: Vec3A) -> Vec3A {
         self $binary_symbol &rhs
       }
     }
 
-    impl<'a> $Trait<f32> for &'a $VectorType {
-      type Output = $VectorType;
+    impl<'a> $Trait<f32> for &'a Vec3A {
+      type Output = Vec3A;
 
-      fn $binary_fn(self, rhs: f32) -> $VectorType {
-        $VectorType {
+      fn $binary_fn(self, rhs: f32) -> Vec3A {
+        Vec3A {
           x: self.x $binary_symbol rhs,
           y: self.y $binary_symbol rhs,
-          z: self.z $binary_symbol rhs
+          z: self.z $binary_symbol rhs,
+          _w: self._w $binary_symbol rhs,
         }
       }
     }
 
-    impl $Trait<f32> for $VectorType {
-      type Output = $VectorType;
+    impl $Trait<f32> for Vec3A {
+      type Output = Vec3A;
 
       #[inline]
-      fn $binary_fn(self, rhs: f32) -> $VectorType {
+      fn $binary_fn(self, rhs: f32) -> Vec3A {
         &self $binary_symbol rhs
       }
     }
 
-    impl $Trait<$VectorType> for f32 {
-      type Output = $VectorType;
+    impl $Trait<Vec3A> for f32 {
+      type Output = Vec3A;
 
-      #[inline]
-      fn $binary_fn(self, rhs: $VectorType) -> $VectorType {
-        &rhs $binary_symbol self
+      fn $binary_fn(self, rhs: Vec3A) -> Vec3A {
+        Vec3A {
+          x: self $binary_symbol rhs.x,
+          y: self $binary_symbol rhs.y,
+          z: self $binary_symbol rhs.z,
+          // The padding lane has no left-hand counterpart here (`self` is
+          // a bare scalar), so it just passes through unchanged rather
+          // than feeding `self` into the arithmetic a second time.
+          _w: rhs._w,
+        }
       }
     }
 
-    impl<'a> $Trait<&'a $VectorType> for f32 {
-      type Output = $VectorType;
+    impl<'a> $Trait<&'a Vec3A> for f32 {
+      type Output = Vec3A;
 
-      #[inline]
-      fn $binary_fn(self, rhs: &'a $VectorType) -> $VectorType {
-        rhs $binary_symbol self
+      fn $binary_fn(self, rhs: &'a Vec3A) -> Vec3A {
+        Vec3A {
+          x: self $binary_symbol rhs.x,
+          y: self $binary_symbol rhs.y,
+          z: self $binary_symbol rhs.z,
+          // The padding lane has no left-hand counterpart here (`self` is
+          // a bare scalar), so it just passes through unchanged rather
+          // than feeding `self` into the arithmetic a second time.
+          _w: rhs._w,
+        }
       }
     }
   };
 }
 
-macro_rules! impl_unary_operations {
-  ($VectorType:ident $Trait:ident $binary_fn:ident $binary_symbol:tt) => {
-
-    impl<'a> $Trait for &'a $VectorType {
-      type Output = $VectorType;
+macro_rules! impl_vec3a_unary_operations {
+  ($Trait:ident $binary_fn:ident $binary_symbol:tt) => {
+    impl<'a> $Trait for &'a Vec3A {
+      type Output = Vec3A;
 
-      fn $binary_fn(self) -> Vec3 {
-        $VectorType {
+      fn $binary_fn(self) -> Vec3A {
+        Vec3A {
           x: $binary_symbol self.x,
           y: $binary_symbol self.y,
           z: $binary_symbol self.z,
+          _w: $binary_symbol self._w,
         }
       }
     }
 
-    impl $Trait for $VectorType {
-      type Output = $VectorType;
+    impl $Trait for Vec3A {
+      type Output = Vec3A;
 
       #[inline]
-      fn $binary_fn(self) -> Vec3 {
+      fn $binary_fn(self) -> Vec3A {
         $binary_symbol &self
       }
     }
   };
 }
 
-macro_rules! impl_op_assign {
-  ($VectorType:ident $TraitAssign:ident $binary_fn:ident $binary_symbol:tt) => {
-
-    impl<'a> $TraitAssign<&'a $VectorType> for $VectorType {
-      fn $binary_fn(&mut self, rhs: &'a $VectorType) {
-        *self = $VectorType {
+macro_rules! impl_vec3a_op_assign {
+  ($TraitAssign:ident $binary_fn:ident $binary_symbol:tt) => {
+    impl<'a> $TraitAssign<&'a Vec3A> for Vec3A {
+      fn $binary_fn(&mut self, rhs: &'a Vec3A) {
+        *self = Vec3A {
           x: self.x $binary_symbol rhs.x,
           y: self.y $binary_symbol rhs.y,
           z: self.z $binary_symbol rhs.z,
+          _w: self._w $binary_symbol rhs._w,
         };
       }
     }
 
-    impl $TraitAssign for $VectorType {
+    impl $TraitAssign for Vec3A {
       #[inline]
-      fn $binary_fn(&mut self, rhs: $VectorType) {
+      fn $binary_fn(&mut self, rhs: Vec3A) {
         *self = *self $binary_symbol &rhs
       }
     }
   };
 }
 
-impl_binary_operations!(Vec3 Add add +);
-impl_binary_operations!(Vec3 Sub sub -);
-impl_binary_operations!(Vec3 Mul mul *);
-impl_binary_operations!(Vec3 Div div /);
+impl_vec3a_binary_operations!(Add add +);
+impl_vec3a_binary_operations!(Sub sub -);
+impl_vec3a_binary_operations!(Mul mul *);
+impl_vec3a_binary_operations!(Div div /);
 
-impl_op_assign!(Vec3 AddAssign add_assign +);
-impl_op_assign!(Vec3 SubAssign sub_assign -);
-impl_op_assign!(Vec3 MulAssign mul_assign *);
-impl_op_assign!(Vec3 DivAssign div_assign /);
+impl_vec3a_op_assign!(AddAssign add_assign +);
+impl_vec3a_op_assign!(SubAssign sub_assign -);
+impl_vec3a_op_assign!(MulAssign mul_assign *);
+impl_vec3a_op_assign!(DivAssign div_assign /);
 
-impl_unary_operations!(Vec3 Neg neg -);
+impl_vec3a_unary_operations!(Neg neg -);
 
 #[cfg(test)]
 mod tests {
@@ -259,182 +686,304 @@ mod tests {
 
     #[test]
     fn add() {
-        let a = Vec3::new(0.0, 1.0, 2.0);
-        let b = Vec3::new(3.0, 4.0, 5.0);
+        let a = Vec3f::new(0.0, 1.0, 2.0);
+        let b = Vec3f::new(3.0, 4.0, 5.0);
         assert_eq!(
-            Vec3::new(0.0, 1.0, 2.0) + Vec3::new(3.0, 4.0, 5.0),
-            Vec3::new(3.0, 5.0, 7.0)
+            Vec3f::new(0.0, 1.0, 2.0) + Vec3f::new(3.0, 4.0, 5.0),
+            Vec3f::new(3.0, 5.0, 7.0)
         );
-        assert_eq!(a + Vec3::new(3.0, 4.0, 5.0), Vec3::new(3.0, 5.0, 7.0));
-        assert_eq!(&a + Vec3::new(3.0, 4.0, 5.0), Vec3::new(3.0, 5.0, 7.0));
-        assert_eq!(&a + &b, Vec3::new(3.0, 5.0, 7.0));
-        assert_eq!(a + &b, Vec3::new(3.0, 5.0, 7.0));
-        assert_eq!(&a + b, Vec3::new(3.0, 5.0, 7.0));
-        assert_eq!(a + b, Vec3::new(3.0, 5.0, 7.0));
+        assert_eq!(a + Vec3f::new(3.0, 4.0, 5.0), Vec3f::new(3.0, 5.0, 7.0));
+        assert_eq!(&a + Vec3f::new(3.0, 4.0, 5.0), Vec3f::new(3.0, 5.0, 7.0));
+        assert_eq!(&a + &b, Vec3f::new(3.0, 5.0, 7.0));
+        assert_eq!(a + &b, Vec3f::new(3.0, 5.0, 7.0));
+        assert_eq!(&a + b, Vec3f::new(3.0, 5.0, 7.0));
+        assert_eq!(a + b, Vec3f::new(3.0, 5.0, 7.0));
 
         // Test for RHS value type
         {
-            let mut c = Vec3::ONE;
+            let mut c = Vec3f::one();
             c += a;
-            assert_eq!(c, Vec3::new(1.0, 2.0, 3.0));
+            assert_eq!(c, Vec3f::new(1.0, 2.0, 3.0));
         }
 
         // Test for RHS borrowed reference
         {
-            let mut c = Vec3::ONE;
+            let mut c = Vec3f::one();
             c += &a;
-            assert_eq!(c, Vec3::new(1.0, 2.0, 3.0));
+            assert_eq!(c, Vec3f::new(1.0, 2.0, 3.0));
         }
     }
 
     #[test]
     fn subtract() {
-        let a = Vec3::new(0.0, 1.0, 2.0);
-        let b = Vec3::new(3.0, 4.0, 5.0);
+        let a = Vec3f::new(0.0, 1.0, 2.0);
+        let b = Vec3f::new(3.0, 4.0, 5.0);
         assert_eq!(
-            Vec3::new(0.0, 1.0, 2.0) - Vec3::new(3.0, 4.0, 5.0),
-            Vec3::new(-3.0, -3.0, -3.0)
+            Vec3f::new(0.0, 1.0, 2.0) - Vec3f::new(3.0, 4.0, 5.0),
+            Vec3f::new(-3.0, -3.0, -3.0)
         );
-        assert_eq!(a - Vec3::new(3.0, 4.0, 5.0), Vec3::new(-3.0, -3.0, -3.0));
-        assert_eq!(&a - Vec3::new(3.0, 4.0, 5.0), Vec3::new(-3.0, -3.0, -3.0));
-        assert_eq!(&a - &b, Vec3::new(-3.0, -3.0, -3.0));
-        assert_eq!(a - &b, Vec3::new(-3.0, -3.0, -3.0));
-        assert_eq!(&a - b, Vec3::new(-3.0, -3.0, -3.0));
-        assert_eq!(a - b, Vec3::new(-3.0, -3.0, -3.0));
+        assert_eq!(a - Vec3f::new(3.0, 4.0, 5.0), Vec3f::new(-3.0, -3.0, -3.0));
+        assert_eq!(&a - Vec3f::new(3.0, 4.0, 5.0), Vec3f::new(-3.0, -3.0, -3.0));
+        assert_eq!(&a - &b, Vec3f::new(-3.0, -3.0, -3.0));
+        assert_eq!(a - &b, Vec3f::new(-3.0, -3.0, -3.0));
+        assert_eq!(&a - b, Vec3f::new(-3.0, -3.0, -3.0));
+        assert_eq!(a - b, Vec3f::new(-3.0, -3.0, -3.0));
 
         // Test for RHS value type
         {
-            let mut c = Vec3::ONE;
+            let mut c = Vec3f::one();
             c -= a;
-            assert_eq!(c, Vec3::new(1.0, 0.0, -1.0));
+            assert_eq!(c, Vec3f::new(1.0, 0.0, -1.0));
         }
 
         // Test for RHS borrowed reference
         {
-            let mut c = Vec3::ONE;
+            let mut c = Vec3f::one();
             c -= &a;
-            assert_eq!(c, Vec3::new(1.0, 0.0, -1.0));
+            assert_eq!(c, Vec3f::new(1.0, 0.0, -1.0));
         }
     }
 
     #[test]
     fn multiply() {
-        let a = Vec3::new(0.0, 1.0, 2.0);
-        let b = Vec3::new(3.0, 4.0, 5.0);
+        let a = Vec3f::new(0.0, 1.0, 2.0);
+        let b = Vec3f::new(3.0, 4.0, 5.0);
         assert_eq!(
-            Vec3::new(0.0, 1.0, 2.0) * Vec3::new(3.0, 4.0, 5.0),
-            Vec3::new(0.0, 4.0, 10.0)
+            Vec3f::new(0.0, 1.0, 2.0) * Vec3f::new(3.0, 4.0, 5.0),
+            Vec3f::new(0.0, 4.0, 10.0)
         );
-        assert_eq!(a * Vec3::new(3.0, 4.0, 5.0), Vec3::new(0.0, 4.0, 10.0));
-        assert_eq!(&a * Vec3::new(3.0, 4.0, 5.0), Vec3::new(0.0, 4.0, 10.0));
-        assert_eq!(&a * &b, Vec3::new(0.0, 4.0, 10.0));
-        assert_eq!(a * &b, Vec3::new(0.0, 4.0, 10.0));
-        assert_eq!(&a * b, Vec3::new(0.0, 4.0, 10.0));
-        assert_eq!(a * b, Vec3::new(0.0, 4.0, 10.0));
+        assert_eq!(a * Vec3f::new(3.0, 4.0, 5.0), Vec3f::new(0.0, 4.0, 10.0));
+        assert_eq!(&a * Vec3f::new(3.0, 4.0, 5.0), Vec3f::new(0.0, 4.0, 10.0));
+        assert_eq!(&a * &b, Vec3f::new(0.0, 4.0, 10.0));
+        assert_eq!(a * &b, Vec3f::new(0.0, 4.0, 10.0));
+        assert_eq!(&a * b, Vec3f::new(0.0, 4.0, 10.0));
+        assert_eq!(a * b, Vec3f::new(0.0, 4.0, 10.0));
 
         // Test for RHS value type
         {
-            let mut c = Vec3::splat(2.0);
+            let mut c = Vec3f::splat(2.0);
             c *= a;
-            assert_eq!(c, 2.0 * a);
+            assert_eq!(c, a * 2.0);
         }
 
         // Test for RHS borrowed reference
         {
-            let mut c = Vec3::splat(2.0);
+            let mut c = Vec3f::splat(2.0);
             c *= &a;
-            assert_eq!(c, 2.0 * a);
+            assert_eq!(c, a * 2.0);
         }
     }
 
     #[test]
     fn divide() {
-        let a = Vec3::new(1.0, 1.0, 2.0);
-        let b = Vec3::new(3.0, 4.0, 5.0);
+        let a = Vec3f::new(1.0, 1.0, 2.0);
+        let b = Vec3f::new(3.0, 4.0, 5.0);
         assert_eq!(
-            Vec3::new(1.0, 1.0, 2.0) / Vec3::new(3.0, 4.0, 5.0),
-            Vec3::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0)
+            Vec3f::new(1.0, 1.0, 2.0) / Vec3f::new(3.0, 4.0, 5.0),
+            Vec3f::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0)
         );
         assert_eq!(
-            a / Vec3::new(3.0, 4.0, 5.0),
-            Vec3::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0)
+            a / Vec3f::new(3.0, 4.0, 5.0),
+            Vec3f::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0)
         );
         assert_eq!(
-            &a / Vec3::new(3.0, 4.0, 5.0),
-            Vec3::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0)
+            &a / Vec3f::new(3.0, 4.0, 5.0),
+            Vec3f::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0)
         );
-        assert_eq!(&a / &b, Vec3::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
-        assert_eq!(a / &b, Vec3::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
-        assert_eq!(&a / b, Vec3::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
-        assert_eq!(a / b, Vec3::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
+        assert_eq!(&a / &b, Vec3f::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
+        assert_eq!(a / &b, Vec3f::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
+        assert_eq!(&a / b, Vec3f::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
+        assert_eq!(a / b, Vec3f::new(1.0 / 3.0, 1.0 / 4.0, 2.0 / 5.0));
 
         // Test for RHS value type
         {
-            let mut c = Vec3::ONE;
+            let mut c = Vec3f::one();
             c /= a;
-            assert_eq!(c, Vec3::new(1.0, 1.0, 0.5));
+            assert_eq!(c, Vec3f::new(1.0, 1.0, 0.5));
         }
 
         // Test for RHS borrowed reference
         {
-            let mut c = Vec3::ONE;
+            let mut c = Vec3f::one();
             c /= &a;
-            assert_eq!(c, Vec3::new(1.0, 1.0, 0.5));
+            assert_eq!(c, Vec3f::new(1.0, 1.0, 0.5));
         }
     }
 
     #[test]
     fn dot() {
-        let a = Vec3::new(2.0, 3.0, 5.0);
-        let b = Vec3::new(7.0, 11.0, 13.0);
-        assert_eq!(Vec3::dot(&a, &b), 2.0 * 7.0 + 3.0 * 11.0 + 5.0 * 13.0);
+        let a = Vec3f::new(2.0, 3.0, 5.0);
+        let b = Vec3f::new(7.0, 11.0, 13.0);
+        assert_eq!(Vec3f::dot(&a, &b), 2.0 * 7.0 + 3.0 * 11.0 + 5.0 * 13.0);
     }
 
     #[test]
     fn cross() {
-        let a = Vec3::new(1.0, 0.0, 0.0);
-        let b = Vec3::new(0.0, 1.0, 0.0);
-        assert_eq!(Vec3::cross(&a, &b), Vec3::new(0.0, 0.0, 1.0));
+        let a = Vec3f::new(1.0, 0.0, 0.0);
+        let b = Vec3f::new(0.0, 1.0, 0.0);
+        assert_eq!(Vec3f::cross(&a, &b), Vec3f::new(0.0, 0.0, 1.0));
     }
 
     #[test]
     fn len() {
-        let a = Vec3::new(3.0, 2.0, 1.0);
+        let a = Vec3f::new(3.0, 2.0, 1.0);
         assert_eq!(a.len(), ((3.0 * 3.0 + 2.0 * 2.0 + 1.0 * 1.0) as f32).sqrt());
 
-        let b = Vec3::splat(0.0);
+        let b = Vec3f::splat(0.0);
         assert_eq!(b.len(), 0.0);
     }
 
     #[test]
     fn normalize() {
-        let a = Vec3::new(3.0, 2.0, 1.0);
+        let a = Vec3f::new(3.0, 2.0, 1.0);
         let len = a.len();
-        assert!((a.normalize().len() - 1.0).abs() < 0.01);
         assert_eq!(a.normalize(), a / len);
+        assert_approx_eq!(a.normalize(), Vec3f::new(0.8017837, 0.5345225, 0.2672612));
+    }
+
+    #[test]
+    fn approx_eq() {
+        let a = Vec3f::new(1.0, 2.0, 3.0);
+        let b = Vec3f::new(1.00001, 2.00001, 3.00001);
+        assert!(a.approx_eq(&b));
+        assert!(!a.approx_eq(&Vec3f::new(1.1, 2.0, 3.0)));
+        assert!(a.approx_eq_eps(&Vec3f::new(1.2, 2.0, 3.0), &Vec3f::splat(0.5)));
+        assert_approx_eq!(a, b);
+        assert_approx_eq!(a, Vec3f::new(1.2, 2.0, 3.0), Vec3f::splat(0.5));
     }
 
     #[test]
     fn with_component() {
-        let a = Vec3::new(3.0, 2.0, 1.0);
-        assert_eq!(a.with_x(4.0), Vec3::new(4.0, 2.0, 1.0));
-        assert_eq!(a.with_y(4.0), Vec3::new(3.0, 4.0, 1.0));
-        assert_eq!(a.with_z(4.0), Vec3::new(3.0, 2.0, 4.0));
+        let a = Vec3f::new(3.0, 2.0, 1.0);
+        assert_eq!(a.with_x(4.0), Vec3f::new(4.0, 2.0, 1.0));
+        assert_eq!(a.with_y(4.0), Vec3f::new(3.0, 4.0, 1.0));
+        assert_eq!(a.with_z(4.0), Vec3f::new(3.0, 2.0, 4.0));
     }
 
     #[test]
     fn min() {
-        let tiny_x = Vec3::new(0.00001, 1000.0, 1000.0);
-        let tiny_y = Vec3::new(1000.0, 0.00001, 1000.0);
-        let tiny_z = Vec3::new(1000.0, 1000.0, 0.00001);
-        assert_eq!(tiny_x.min(&tiny_y).min(&tiny_z), Vec3::splat(0.00001));
+        let tiny_x = Vec3f::new(0.00001, 1000.0, 1000.0);
+        let tiny_y = Vec3f::new(1000.0, 0.00001, 1000.0);
+        let tiny_z = Vec3f::new(1000.0, 1000.0, 0.00001);
+        assert_eq!(tiny_x.min(&tiny_y).min(&tiny_z), Vec3f::splat(0.00001));
+    }
+
+    #[test]
+    fn swizzle3() {
+        let a = Vec3f::new(1.0, 2.0, 3.0);
+        assert_eq!(a.swizzle3::<2, 1, 0>(), Vec3f::new(3.0, 2.0, 1.0));
+        assert_eq!(a.zyx(), Vec3f::new(3.0, 2.0, 1.0));
+        assert_eq!(a.xxx(), Vec3f::new(1.0, 1.0, 1.0));
+        assert_eq!(a.yzx(), Vec3f::new(2.0, 3.0, 1.0));
+    }
+
+    #[test]
+    fn swizzle2() {
+        let a = Vec3f::new(1.0, 2.0, 3.0);
+        assert_eq!(a.swizzle2::<0, 2>(), (1.0, 3.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn swizzle3_out_of_range() {
+        let a = Vec3f::new(1.0, 2.0, 3.0);
+        a.swizzle3::<0, 1, 3>();
     }
 
     #[test]
     fn max() {
-        let big_x = Vec3::new(1000.0, 0.00001, 0.00001);
-        let big_y = Vec3::new(0.00001, 1000.0, 0.00001);
-        let big_z = Vec3::new(0.00001, 0.00001, 1000.0);
-        assert_eq!(big_x.max(&big_y).max(&big_z), Vec3::splat(1000.0));
+        let big_x = Vec3f::new(1000.0, 0.00001, 0.00001);
+        let big_y = Vec3f::new(0.00001, 1000.0, 0.00001);
+        let big_z = Vec3f::new(0.00001, 0.00001, 1000.0);
+        assert_eq!(big_x.max(&big_y).max(&big_z), Vec3f::splat(1000.0));
+    }
+
+    #[test]
+    fn mul_add_matches_origin_plus_t_dir() {
+        let origin = Vec3f::new(1.0, 2.0, 3.0);
+        let dir = Vec3f::new(4.0, 5.0, 6.0);
+        let t = 2.0;
+        assert_eq!(
+            dir.mul_add(Vec3f::splat(t), origin),
+            Vec3f::new(9.0, 12.0, 15.0)
+        );
+    }
+
+    #[test]
+    fn mul_add_differs_from_naive_on_near_cancellation() {
+        let a = Vec3f::splat(1.0e8);
+        let mul = Vec3f::splat(1.000_000_1);
+        let add = Vec3f::splat(-1.0e8);
+
+        let fused = a.mul_add(mul, add);
+        let naive = a * mul + add;
+
+        assert_ne!(fused, naive);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Vec3f::new(0.0, 0.0, 0.0);
+        let b = Vec3f::new(10.0, 20.0, 30.0);
+        assert_eq!(a.lerp(b, 0.5), Vec3f::new(5.0, 10.0, 15.0));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(
+            a.lerp_vec3(b, Vec3f::new(0.0, 0.5, 1.0)),
+            Vec3f::new(0.0, 10.0, 30.0)
+        );
+    }
+
+    #[test]
+    fn vec3a_arithmetic() {
+        let a = Vec3A::new(0.0, 1.0, 2.0);
+        let b = Vec3A::new(3.0, 4.0, 5.0);
+        assert_eq!(a + b, Vec3A::new(3.0, 5.0, 7.0));
+        assert_eq!(a - b, Vec3A::new(-3.0, -3.0, -3.0));
+        assert_eq!(a * 2.0, Vec3A::new(0.0, 2.0, 4.0));
+        assert_eq!(2.0 * a, Vec3A::new(0.0, 2.0, 4.0));
+
+        let v = Vec3A::new(1.0, 2.0, 3.0);
+        assert_eq!(10.0 - v, Vec3A::new(9.0, 8.0, 7.0));
+        assert_eq!(10.0 / v, Vec3A::new(10.0, 5.0, 10.0 / 3.0));
+    }
+
+    #[test]
+    fn vec3a_dot_ignores_padding_lane() {
+        // Non-zero padding, so a dot that read `_w` would change the result.
+        let a = Vec3A { _w: 7.0, ..Vec3A::new(1.0, 2.0, 3.0) };
+        let b = Vec3A { _w: 9.0, ..Vec3A::new(4.0, 5.0, 6.0) };
+        assert_eq!(Vec3A::dot(&a, &b), 1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0);
+    }
+
+    #[test]
+    fn vec3a_cross() {
+        let a = Vec3A::new(1.0, 0.0, 0.0);
+        let b = Vec3A::new(0.0, 1.0, 0.0);
+        assert_eq!(Vec3A::cross(&a, &b), Vec3A::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn vec3_vec3a_roundtrip() {
+        let a = Vec3f::new(1.0, 2.0, 3.0);
+        let a_simd: Vec3A = a.into();
+        let back: Vec3f = a_simd.into();
+        assert_eq!(a, back);
+    }
+
+    struct World;
+    struct Camera;
+
+    #[test]
+    fn unit_tags_keep_same_unit_ops_working_and_cast_unit_reinterprets() {
+        let a: Vec3<f32, World> = Vec3::new(0.0, 1.0, 2.0);
+        let b: Vec3<f32, World> = Vec3::new(3.0, 4.0, 5.0);
+
+        // Same-unit ops compile and behave like the untagged Vec3f.
+        assert_eq!(a + b, Vec3::new(3.0, 5.0, 7.0));
+
+        // cast_unit reinterprets the tag without touching the components.
+        let in_camera_space: Vec3<f32, Camera> = a.cast_unit();
+        assert_eq!(in_camera_space, Vec3::new(0.0, 1.0, 2.0));
     }
 }